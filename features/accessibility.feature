@@ -0,0 +1,42 @@
+Feature: screen-reader friendly output
+
+  As a developer using a screen reader
+  I want Tertestrial to produce stable, spelled-out output
+  So that I can follow my test runs without relying on colors or screen updates.
+
+  - run "tertestrial --a11y" or set TERTESTRIAL_A11Y to enable this mode,
+    values like "0" or "false" leave it off
+  - in this mode, Tertestrial doesn't clear the screen or animate it
+  - it spells out "RUNNING", "PASS", and "FAIL"
+
+
+  Scenario: a passing test
+    Given Tertestrial runs with the argument "--a11y" and the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "RUNNING: echo running all tests"
+    And I see "PASS: exit code: 0"
+    And the process is still running
+
+
+  Scenario: a failing test
+    Given the environment variable "TERTESTRIAL_A11Y" is "1"
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'exit 1'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "FAIL: exit code: 1"
+    And the process is still running
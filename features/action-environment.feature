@@ -0,0 +1,35 @@
+Feature: environment variables of actions
+
+  As a developer debugging a single test
+  I want actions to set environment variables for their commands
+  So that only some commands run with settings like RUST_BACKTRACE.
+
+  - the "env" section of an action defines environment variables for its command
+  - their values can contain placeholders
+  - they override variables from .env files and the environment of the Tertestrial server
+
+
+  Scenario: setting environment variables for an action
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo testing all with backtraces [$RUST_BACKTRACE]'
+
+        - match:
+            filename: '\.rs$'
+          command: 'echo testing $TEST_FILE with backtraces [$RUST_BACKTRACE]'
+          env:
+            RUST_BACKTRACE: 1
+            TEST_FILE: '{{fileStem}}'
+      """
+    When sending the command:
+      """
+      {"filename": "parser.rs"}
+      """
+    Then I see "testing parser with backtraces [1]"
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "testing all with backtraces []"
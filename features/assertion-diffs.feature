@@ -0,0 +1,27 @@
+Feature: diffs of failed assertions
+
+  As a developer reading test failures
+  I want to see which words of the expected and actual values differ
+  So that I can spot the problem without comparing long values character by character.
+
+  - actions with "diffAssertions: true" print word-level diffs
+    of the expected and actual values of failed assertions
+  - this understands the assert_eq! output of Rust and the expected/received output of Jest and Vitest
+  - removed words are marked like [-this-], added ones like {+this+}
+
+
+  Scenario: a failed Jest assertion
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'printf "Expected: user one\nReceived: user two\n" ; exit 1'
+          diffAssertions: true
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "- user [-one-]"
+    And I see "+ user {+two+}"
+    And the process is still running
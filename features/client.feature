@@ -0,0 +1,37 @@
+Feature: identifying clients
+
+  As a developer using several editors with the same Tertestrial server
+  I want to see which client sent a command
+  So that I can tell where a test run came from.
+
+  - commands can contain a "client" key identifying the sender
+  - Tertestrial prints the client and ignores it when matching actions
+
+
+  Background:
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+
+
+  Scenario: client given as a name
+    When sending the command:
+      """
+      {"client": "vim"}
+      """
+    Then I see "command from vim"
+    And I see "running all tests"
+    And the process is still running
+
+
+  Scenario: client given with details
+    When sending the command:
+      """
+      {"client": {"editor": "vim", "pid": 1234}}
+      """
+    Then I see "command from editor=vim pid=1234"
+    And I see "running all tests"
+    And the process is still running
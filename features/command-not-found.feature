@@ -0,0 +1,42 @@
+Feature: commands that don't exist
+
+  As a developer whose configuration refers to a tool that isn't installed
+  I want Tertestrial to tell me that the tool is missing
+  So that I don't mistake this for a failing test.
+
+  - when the executable of a command cannot be found,
+    Tertestrial names the missing executable
+  - commands that run in a shell and exit with code 127 likely miss an executable,
+    which Tertestrial takes from the error message of the shell if it can
+  - with "--verbose", Tertestrial also shows the PATH it searched
+
+
+  Scenario: running a command whose executable does not exist
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'FOO=bar zonk --all'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "Error: exit code 127, likely because the executable zonk wasn't found — is it installed?"
+    And the process is still running
+
+
+  Scenario: showing the searched PATH
+    Given Tertestrial runs with the argument "--verbose" and the configuration:
+      """
+      actions:
+        - match:
+          command: 'zonk --all'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "wasn't found — is it installed?"
+    And I see "searched in PATH:"
+    And the process is still running
@@ -0,0 +1,52 @@
+Feature: aliases for command keys
+
+  As a developer sending commands by hand or from a minimal editor integration
+  I want to use short names for the keys of commands
+  So that the messages I send are quick to type.
+
+  - the "aliases" section maps short key names to the full names
+  - Tertestrial replaces aliased keys before processing a command
+
+
+  Scenario: sending a command with aliased keys
+    Given Tertestrial runs with the configuration:
+      """
+      aliases:
+        f: filename
+        l: line
+
+      actions:
+        - match:
+            filename: '\.js$'
+            line: '\d+'
+          command: 'echo testing {{filename}} at line {{line}}'
+      """
+    When sending the command:
+      """
+      {"f": "one.js", "l": 12}
+      """
+    Then I see "testing one.js at line 12"
+    And the process is still running
+
+
+  Scenario: repeating the last test via an alias
+    Given Tertestrial runs with the configuration:
+      """
+      aliases:
+        r: repeatLastTest
+
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "running all tests"
+    When sending the command:
+      """
+      {"r": true}
+      """
+    Then I see "running all tests"
+    And the process is still running
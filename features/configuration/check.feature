@@ -0,0 +1,59 @@
+Feature: checking the configuration file
+
+  As a developer editing the Tertestrial configuration file
+  I want to find all problems in it at once
+  So that I don't discover them one by one while running tests.
+
+  - run "tertestrial config check" to report all problems in the configuration file
+  - this includes invalid actions, invalid regular expressions,
+    placeholders that nothing provides a value for,
+    and actions that never run because a later action matches all their commands
+  - the command exits with code 2 if it finds problems
+
+
+  Scenario: a valid configuration
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'mocha {{filename}}'
+      """
+    When running 'tertestrial config check'
+    Then I see "the configuration is valid"
+
+
+  Scenario: a configuration with problems
+    Given a file "tertestrial.yml" with the content:
+      """
+      strictConfig: true
+      actions:
+        - match:
+            filename: '(\.js$'
+          command: 'mocha {{filename}}'
+        - match:
+            filename: '\.rb$'
+          command: 'rspec {{filname}}'
+      """
+    When starting 'tertestrial config check'
+    Then I see "found 2 problem(s) in the configuration"
+    And I see "action 1 in action set default has an invalid regular expression"
+    And I see "action 2 in action set default uses the placeholder {{filname}}, which nothing provides a value for"
+    And the process ends with exit code 2
+
+
+  Scenario: an action that never runs
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '_spec\.js$'
+          command: 'mocha --reporter spec {{filename}}'
+        - match:
+            filename: '.*'
+          command: 'mocha {{filename}}'
+      """
+    When starting 'tertestrial config check'
+    Then I see "found 1 problem(s) in the configuration"
+    And I see "action 1 in action set default never runs, because action 2 matches all commands that it matches"
+    And the process ends with exit code 2
@@ -0,0 +1,28 @@
+Feature: choosing the configuration file
+
+  As a developer experimenting with several configurations
+  I want to choose the configuration file when starting Tertestrial
+  So that I can switch between them without renaming files.
+
+  - run "tertestrial --config=<path>" to use the given configuration file
+
+
+  Scenario: using an alternative configuration file
+    Given a file "experiment.yml" with the content:
+      """
+      actions:
+        - match:
+          command: 'echo using the experimental configuration'
+      """
+    And Tertestrial runs with the argument "--config=experiment.yml" and the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo using the default configuration'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "using the experimental configuration"
+    And the process is still running
@@ -0,0 +1,28 @@
+Feature: constants
+
+  As a developer whose commands repeat the same values
+  I want to define these values once
+  So that I only have to change them in one place.
+
+  - the "constants" section defines values
+    that all commands can use as placeholders
+  - keys of the command take precedence over constants
+
+
+  Scenario: using a constant in a command
+    Given Tertestrial runs with the configuration:
+      """
+      constants:
+        composeFile: docker/compose.test.yml
+
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}} with {{composeFile}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "testing one.js with docker/compose.test.yml"
+    And the process is still running
@@ -0,0 +1,68 @@
+Feature: defaults for all actions
+
+  As a developer with many similar actions
+  I want to define their common settings once
+  So that I don't have to repeat them in every action.
+
+  - the "defaults" section contains settings for all actions that don't define them
+  - environment variables merge with the default ones
+  - defaults cannot provide what makes actions what they are, like "match" or "command"
+
+
+  Scenario: applying defaults
+    Given Tertestrial runs with the configuration:
+      """
+      defaults:
+        env:
+          RUST_BACKTRACE: 1
+          RUST_LOG: info
+
+      actions:
+        - match:
+          command: 'echo testing all with backtraces $RUST_BACKTRACE and logs $RUST_LOG'
+
+        - match:
+            filename: '\.rs$'
+          command: 'echo testing {{filename}} with backtraces $RUST_BACKTRACE and logs $RUST_LOG'
+          env:
+            RUST_LOG: debug
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "testing all with backtraces 1 and logs info"
+    When sending the command:
+      """
+      {"filename": "parser.rs"}
+      """
+    Then I see "testing parser.rs with backtraces 1 and logs debug"
+
+
+  Scenario: unknown keys in the defaults
+    Given Tertestrial runs with the configuration:
+      """
+      defaults:
+        zonk: 1
+
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    Then I see "unknown keys: defaults.zonk"
+    And the process ends
+
+
+  Scenario: defaults for what actions are
+    Given a file "tertestrial.yml" with the content:
+      """
+      defaults:
+        command: 'echo testing'
+
+      actions:
+        - match:
+          command: 'echo running'
+      """
+    When trying to start tertestrial
+    Then I see "Error: unknown keys: defaults.command"
+    And the process ends
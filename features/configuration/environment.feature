@@ -0,0 +1,37 @@
+Feature: running commands inside a development environment
+
+  As a developer whose toolchain is provided by direnv, mise, or Nix
+  I want Tertestrial to run commands inside that environment
+  So that they see the same toolchain as my shell.
+
+  - the "environment" setting wraps commands in "direnv exec", "mise exec", or "nix develop"
+  - it can be set for the whole configuration file or for individual actions
+  - "auto" detects the environment from a ".envrc", "flake.nix",
+    "mise.toml", or ".tool-versions" file
+
+
+  Scenario: unknown environment
+    Given a file "tertestrial.yml" with the content:
+      """
+      environment: zonk
+      actions: []
+      """
+    When trying to start tertestrial
+    Then I see "Error: unknown environment: zonk, must be one of auto, direnv, mise, nix"
+    And the process ends
+
+
+  Scenario: no environment detected
+    Given Tertestrial runs with the configuration:
+      """
+      environment: auto
+      actions:
+        - match:
+          command: 'echo running without environment'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "running without environment"
+    And the process is still running
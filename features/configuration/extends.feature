@@ -0,0 +1,112 @@
+Feature: extending configurations
+
+  As a developer working in many similar code bases
+  I want their configurations to inherit from a shared base configuration
+  So that I only have to define what is different in each of them.
+
+  - "extends" names a configuration file relative to the configuration,
+    or a built-in configuration
+  - local actions take precedence over equally specific inherited ones
+  - sections like "constants" merge key by key
+
+
+  Scenario: inheriting actions
+    Given a file "base.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo base testing {{filename}}'
+      """
+    And Tertestrial runs with the configuration:
+      """
+      extends: base.yml
+
+      actions:
+        - match:
+            filename: '\.rb$'
+          command: 'echo local testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "base testing one.js"
+    When sending the command:
+      """
+      {"filename": "two.rb"}
+      """
+    Then I see "local testing two.rb"
+
+
+  Scenario: overriding inherited actions and constants
+    Given a file "base.yml" with the content:
+      """
+      constants:
+        runner: mocha
+        reporter: dot
+
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo base {{runner}} {{reporter}} {{filename}}'
+      """
+    And Tertestrial runs with the configuration:
+      """
+      extends: base.yml
+
+      constants:
+        reporter: spec
+
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo local {{runner}} {{reporter}} {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "local mocha spec one.js"
+
+
+  Scenario: overridden inherited actions are not unreachable
+    Given a file "base.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo base testing {{filename}}'
+      """
+    And a file "tertestrial.yml" with the content:
+      """
+      extends: base.yml
+
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo local testing {{filename}}'
+      """
+    When running 'tertestrial config check'
+    Then I see "the configuration is valid"
+
+  Scenario: extending a missing configuration
+    Given Tertestrial runs with the configuration:
+      """
+      extends: missing.yml
+      """
+    Then I see "cannot find the configuration missing.yml to extend"
+    And the process ends
+
+
+  Scenario: configurations that extend each other
+    Given a file "base.yml" with the content:
+      """
+      extends: tertestrial.yml
+      """
+    And Tertestrial runs with the configuration:
+      """
+      extends: base.yml
+      """
+    Then I see "the configuration files extend each other"
+    And the process ends
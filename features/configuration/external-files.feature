@@ -0,0 +1,80 @@
+Feature: files outside the project
+
+  As a developer with several folders open in my editor
+  I want to know clearly when I trigger a test for a file that doesn't belong to the project
+  So that I don't have to wonder why no action matches.
+
+  - commands about files outside the project fail with a clear error
+  - "externalRoots" maps directories outside the project to where the project contains them
+  - "outsideAction" names the action to run for files outside the project instead
+  - "pathRewrites" translates the paths of incoming commands via regular expressions,
+    for example from the paths inside a container to those on the host
+
+
+  Scenario: triggering a file outside the project
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "/elsewhere/one.js"}
+      """
+    Then I see "is outside the project"
+    And the process is still running
+
+
+  Scenario: mapping an external root
+    Given Tertestrial runs with the configuration:
+      """
+      externalRoots:
+        /elsewhere/lib: vendor/lib
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "/elsewhere/lib/one.js"}
+      """
+    Then I see "testing vendor/lib/one.js"
+    And the process is still running
+
+
+  Scenario: running a designated action for files outside the project
+    Given Tertestrial runs with the configuration:
+      """
+      outsideAction: outside
+      actions:
+        - name: outside
+          command: 'echo not part of this project'
+      """
+    When sending the command:
+      """
+      {"filename": "/elsewhere/one.js"}
+      """
+    Then I see "not part of this project"
+    And the process is still running
+
+
+  Scenario: rewriting container paths
+    Given Tertestrial runs with the configuration:
+      """
+      pathRewrites:
+        - pattern: '^/workspace/'
+          replacement: ''
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "/workspace/src/one.js"}
+      """
+    Then I see "testing src/one.js"
+    And the process is still running
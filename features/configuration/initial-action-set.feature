@@ -0,0 +1,44 @@
+Feature: choosing the initial action set
+
+  As a developer switching between quick unit tests and the full test suite
+  I want to choose the action set when starting Tertestrial
+  So that I don't have to edit the configuration or send a command first.
+
+  - run "tertestrial --action-set=<name>" to start with the action set with the given name
+  - editors can switch action sets later via the "actionSet" command
+
+
+  Scenario: starting with another action set
+    Given Tertestrial runs with the argument "--action-set=full" and the configuration:
+      """
+      actions:
+        fast:
+          - match:
+              filename: '\.js$'
+            command: 'echo unit testing {{filename}}'
+
+        full:
+          - match:
+              filename: '\.js$'
+            command: 'echo fully testing {{filename}}'
+      """
+    Then I see "Activating action set full"
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "fully testing one.js"
+    And the process is still running
+
+
+  Scenario: starting with a non-existing action set
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        fast:
+          - match:
+            command: 'echo unit testing'
+      """
+    When starting 'tertestrial --action-set=zonk'
+    Then I see "Error: action set zonk does not exist"
+    And the process ends with exit code 4
@@ -0,0 +1,48 @@
+Feature: invalid actions
+
+  As a developer sharing a large configuration file with my team
+  I want a single broken action to not stop everybody's workflow
+  So that we can keep working while somebody fixes it.
+
+  - by default, Tertestrial refuses configuration files with invalid actions
+  - with "strictConfig: false", it ignores the invalid actions and prints a warning
+
+
+  Scenario: invalid action in strict mode
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+      """
+    When trying to start tertestrial
+    Then I see:
+      """
+      Error: invalid configuration:
+      action 1 in action set default has no command
+      """
+    And the process ends
+    And there is no file ".tertestrial.tmp"
+
+
+  Scenario: invalid action in lenient mode
+    Given Tertestrial runs with the configuration:
+      """
+      strictConfig: false
+      actions:
+        - match:
+            filename: '(unclosed'
+          command: 'echo never runs'
+
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    Then I see "Warning: ignoring invalid actions:"
+    And I see "action 1 in action set default has an invalid regular expression for filename"
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "testing one.js"
+    And the process is still running
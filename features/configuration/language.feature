@@ -4,7 +4,7 @@ Feature: flexible configuration file languages
   I want to be able to create the configuration file in the language of my choice
   So that I can use Tertestrial even if I don't understand YML.
 
-  - the configuration file can be written in any language
+  - the configuration file can be written in TOML or any language
     that compiles to JS
 
 
@@ -50,3 +50,43 @@ Feature: flexible configuration file languages
       """
     Then I see "Running Mocha with one.js"
     And the process is still running
+
+
+  Scenario: TOML config file
+    Given Tertestrial runs with the configuration file "tertestrial.toml":
+      """
+      [[actions]]
+      command = "echo Running cargo test with {{filename}}"
+
+        [actions.match]
+        filename = '\.rs$'
+      """
+    When sending the command:
+      """
+      {"filename": "one.rs"}
+      """
+    Then I see "Running cargo test with one.rs"
+    And the process is still running
+
+
+  Scenario: JSON config file with comments and trailing commas
+    Given Tertestrial runs with the configuration file "tertestrial.json":
+      """
+      {
+        // runs the unit tests
+        "actions": [
+          {
+            "match": {
+              "filename": ".js$",
+            },
+            "command": "echo Running Mocha with {{filename}}",
+          },
+        ],
+      }
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "Running Mocha with one.js"
+    And the process is still running
@@ -0,0 +1,45 @@
+Feature: migrating the configuration file
+
+  As a developer with an older configuration file
+  I want Tertestrial to upgrade it to the current configuration format
+  So that I don't have to learn what changed.
+
+  - the "version" key names the version of the configuration format, files without it use version 1
+  - run "tertestrial config migrate" to upgrade the configuration file in place
+  - Tertestrial refuses configuration files from newer versions of the format
+
+
+  Scenario: migrating a configuration file without a version
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    When running 'tertestrial config migrate'
+    Then I see "upgraded"
+    And the file "tertestrial.yml" contains "version: 1"
+
+
+  Scenario: migrating a current configuration file
+    Given a file "tertestrial.yml" with the content:
+      """
+      version: 1
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    When running 'tertestrial config migrate'
+    Then I see "uses the current configuration format already"
+
+
+  Scenario: a configuration file from a newer version
+    Given a file "tertestrial.yml" with the content:
+      """
+      version: 2
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    When trying to start tertestrial
+    Then I see "the configuration uses version 2 of the configuration format, this Tertestrial supports up to version 1"
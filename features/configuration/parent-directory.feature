@@ -0,0 +1,25 @@
+Feature: configuration files in parent directories
+
+  As a developer working in a subdirectory of my code base
+  I want to start Tertestrial where I am
+  So that I don't have to change to the root directory first.
+
+  - Tertestrial uses the configuration file of the nearest parent directory that has one
+  - it runs in that directory, so that the pipe and all file paths are relative to it
+
+
+  Scenario: starting in a subdirectory
+    Given Tertestrial runs in the subdirectory "src" with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}} in $(basename $(pwd))'
+      """
+    When sending the command:
+      """
+      {"filename": "src/one.js"}
+      """
+    Then I see "using the configuration file"
+    And I see "testing src/one.js in "
+    And the process is still running
@@ -5,6 +5,11 @@ Feature: reloading configuration
   So that I can customize my configuration without having to restart the tool manually.
 
   - any change to tertestrial.yml causes it to reload the configuration
+  - editors can also send '{"reloadConfig": true}' to reload it
+  - after reloading, Tertestrial prints the actions of the new configuration
+    and which actions were added (+), removed (-), or modified (~)
+  - if the new configuration is invalid, for example while it is being edited,
+    Tertestrial prints the problem and keeps using the previous configuration
 
 
   Scenario: the configuration updates without a previous test run
@@ -25,6 +30,12 @@ Feature: reloading configuration
           command: 'echo Running Mocha with {{filename}}'
       """
     Then I see "Reloading configuration"
+    And I see:
+      """
+      changes to the actions:
+      default:
+        + filename: \.js$  ->  echo Running Mocha with {{filename}}
+      """
     When sending the command:
       """
       {"filename": "one.js"}
@@ -59,3 +70,63 @@ Feature: reloading configuration
     And I see "Running all tests"
     And the process is still running
 
+
+
+  Scenario: reloading the configuration on request
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo Running Mocha with {{filename}}'
+      """
+    When sending the command:
+      """
+      {"reloadConfig": true}
+      """
+    Then I see "Reloading configuration"
+    And I see "filename: \.js$  ->  echo Running Mocha with {{filename}}"
+    And I see "the actions did not change"
+    And the process is still running
+
+
+  Scenario: an invalid edit while running
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo Running all tests'
+      """
+    When updating the configuration to:
+      """
+      actions:
+        - match:
+            filename: [unclosed
+      """
+    Then I see "cannot reload the configuration, keeping the previous one"
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "Running all tests"
+    And the process is still running
+
+
+  Scenario: reloading an invalid action on request
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo Running all tests'
+      """
+    When updating the configuration to:
+      """
+      actions:
+        - match:
+      """
+    And sending the command:
+      """
+      {"reloadConfig": true}
+      """
+    Then I see "cannot reload the configuration, keeping the previous one: invalid configuration"
+    And the process is still running
@@ -0,0 +1,24 @@
+Feature: JSON Schema of the configuration file
+
+  As a developer writing a Tertestrial configuration file
+  I want my editor to know the structure of that file
+  So that it can offer completion and validation while I edit it.
+
+  - run "tertestrial schema" or "tertestrial config schema"
+    to print the JSON Schema of the configuration file
+
+
+  Scenario: printing the schema
+    When running 'tertestrial config schema'
+    Then I see:
+      """
+        "title": "Tertestrial configuration",
+      """
+
+
+  Scenario: printing the schema via the short command
+    When running 'tertestrial schema'
+    Then I see:
+      """
+        "title": "Tertestrial configuration",
+      """
@@ -0,0 +1,45 @@
+Feature: showing the effective configuration
+
+  As a developer combining several configuration files
+  I want to see the configuration that Tertestrial actually uses
+  So that I can verify how they merge, and process it with other tools.
+
+  - run "tertestrial config show" to print the effective configuration as JSON
+  - it contains the extended and user-level configurations and the expanded templates,
+    and Tertestrial can load it as a configuration file
+
+
+  Scenario: showing an extended configuration
+    Given a file "base.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'mocha {{filename}}'
+      """
+    And a file "tertestrial.yml" with the content:
+      """
+      extends: base.yml
+      actions:
+        - match:
+            filename: '\.rb$'
+          command: 'rspec {{filename}}'
+      """
+    When running 'tertestrial config show'
+    Then I see:
+      """
+        "actions": [
+          {
+            "match": {
+              "filename": "\\.js$"
+            },
+            "command": "mocha {{filename}}"
+          },
+          {
+            "match": {
+              "filename": "\\.rb$"
+            },
+            "command": "rspec {{filename}}"
+          }
+        ],
+      """
@@ -0,0 +1,108 @@
+Feature: action templates
+
+  As a developer working on a large code base with many similar parts
+  I want to define parametrized actions once and instantiate them several times
+  So that my configuration file stays short and easy to maintain.
+
+  - the "templates" section defines actions with placeholders for parameters
+  - an action with a "template" key is replaced by that template,
+    with the parameters given in "with" filled in
+  - other keys of that action, like "name" or "timeout", override those of the template
+  - environment variables like "{{env.NAME}}" get filled in when the command runs
+
+
+  Scenario: instantiating a template
+    Given Tertestrial runs with the configuration:
+      """
+      templates:
+        jestFile:
+          match:
+            filename: '^{{project}}/.*\.js$'
+          command: 'echo testing {{filename}} in project {{project}}'
+
+      actions:
+        - template: jestFile
+          with:
+            project: web
+
+        - template: jestFile
+          with:
+            project: api
+      """
+    When sending the command:
+      """
+      {"filename": "api/one.js"}
+      """
+    Then I see "testing api/one.js in project api"
+    And the process is still running
+
+
+  Scenario: overriding settings of a template
+    Given Tertestrial runs with the configuration:
+      """
+      templates:
+        failingTests:
+          match:
+          command: 'echo testing project {{project}} ; exit 1'
+
+      actions:
+        - template: failingTests
+          ignoreExitCodes: [1]
+          with:
+            project: web
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "testing project web"
+    And I see "exit code: 1 (treated as success)"
+    And the process is still running
+
+
+  Scenario: naming an instance of a template
+    Given a file "tertestrial.yml" with the content:
+      """
+      templates:
+        projectTests:
+          command: 'echo testing project {{project}}'
+
+      actions:
+        - template: projectTests
+          name: web-tests
+          with:
+            project: web
+      """
+    When starting 'tertestrial run web-tests'
+    Then I see "testing project web"
+    And the process ends with exit code 0
+
+
+  Scenario: environment variables in templates
+    Given the environment variable "TEST_DB" is "sqlite"
+    And a file "tertestrial.yml" with the content:
+      """
+      templates:
+        jestFile:
+          match:
+            filename: '^{{project}}/.*\.js$'
+          command: 'echo testing {{filename}} against {{env.TEST_DB}}'
+
+      actions:
+        - template: jestFile
+          with:
+            project: web
+      """
+    When running 'tertestrial config show'
+    Then I see "echo testing {{filename}} against {{env.TEST_DB}}"
+
+
+  Scenario: unknown template
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - template: zonk
+      """
+    When trying to start tertestrial
+    Then I see "Error: unknown template: zonk"
+    And the process ends
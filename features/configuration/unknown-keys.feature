@@ -0,0 +1,46 @@
+Feature: unknown keys
+
+  As a developer editing the configuration file by hand
+  I want to learn about misspelled keys
+  So that a typo like "comand" doesn't silently disable part of my configuration.
+
+  - by default, Tertestrial refuses configuration files with keys it doesn't know,
+    and names them with their path
+  - it suggests the known key that an unknown key most likely misspells
+  - with "strictConfig: false", it prints a warning instead,
+    so that configuration files can contain keys of newer Tertestrial versions
+
+
+  Scenario: unknown keys in strict mode
+    Given a file "tertestrial.yml" with the content:
+      """
+      aliasses:
+        f: filename
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          sucessOutput: 'passed'
+      """
+    When trying to start tertestrial
+    Then I see "Error: unknown keys: aliasses (did you mean aliases?), actions[1].sucessOutput (did you mean successOutput?)"
+    And the process ends
+
+
+  Scenario: unknown keys in lenient mode
+    Given Tertestrial runs with the configuration:
+      """
+      strictConfig: false
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          sucessOutput: 'passed'
+      """
+    Then I see "Warning: ignoring unknown keys: actions[1].sucessOutput (did you mean successOutput?)"
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "testing one.js"
+    And the process is still running
@@ -0,0 +1,29 @@
+Feature: unreachable actions
+
+  As a developer adding actions to a growing configuration file
+  I want to know when an action can never run
+  So that I don't wonder why my editor runs a different command.
+
+  - equally specific actions run in the order of the configuration file, with the last one winning
+  - Tertestrial warns about actions that never run,
+    because a later action with the same keys matches all the commands they match
+
+
+  Scenario: an action shadowed by a later one
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+        - match:
+            filename: '\.js$'
+          command: 'echo testing again {{filename}}'
+      """
+    Then I see "Warning: some actions never run:"
+    And I see "action 1 in action set default never runs, because action 2 matches all commands that it matches"
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "testing again one.js"
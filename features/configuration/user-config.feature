@@ -0,0 +1,70 @@
+Feature: user-level configuration
+
+  As a developer with personal preferences
+  I want fallback actions that apply to all my code bases
+  So that I don't have to copy them into every repository.
+
+  - Tertestrial also loads the configuration file "config.yml" (or .json, .json5, .toml)
+    in ~/.config/tertestrial, or $XDG_CONFIG_HOME/tertestrial if set
+  - its actions are fallbacks for all action sets of the project:
+    project actions take precedence over equally specific user-level actions
+  - user-level actions don't count when numbering actions in messages,
+    and overriding them doesn't make them unreachable
+  - sections like "constants" merge key by key, with the project values winning
+
+
+  Background:
+    Given a directory ".config"
+    And a directory ".config/tertestrial"
+    And a file ".config/tertestrial/config.yml" with the content:
+      """
+      actions:
+        - match:
+          command: 'echo user-level make test'
+
+        - match:
+            filename: '\.js$'
+          command: 'echo user-level testing {{filename}}'
+      """
+
+
+  Scenario: using a user-level fallback action
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.rb$'
+          command: 'echo project testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "user-level make test"
+
+
+  Scenario: project actions take precedence
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo project testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "project testing one.js"
+
+
+  Scenario: overriding user-level actions is not a problem
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo project testing {{filename}}'
+      """
+    When running 'tertestrial config check'
+    Then I see "the configuration is valid"
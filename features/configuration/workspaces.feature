@@ -0,0 +1,42 @@
+Feature: workspaces
+
+  As a developer working in a monorepo
+  I want each part of the code base to have its own configuration
+  So that I don't have to express everything in one big list of actions.
+
+  - the "workspaces" section maps path prefixes to configuration files
+  - commands for files under such a prefix use that configuration file,
+    with the filename relative to the prefix
+  - the command runs in the directory of the prefix
+
+
+  Scenario: routing a command to a workspace
+    Given a directory "api"
+    And a file "api/tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}} in $(basename $(pwd))'
+      """
+    And Tertestrial runs with the configuration:
+      """
+      workspaces:
+        api: api/tertestrial.yml
+
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}} in the root'
+      """
+    When sending the command:
+      """
+      {"filename": "api/one.js"}
+      """
+    Then I see "testing one.js in api"
+    When sending the command:
+      """
+      {"filename": "two.js"}
+      """
+    Then I see "testing two.js in the root"
+    And the process is still running
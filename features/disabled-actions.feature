@@ -0,0 +1,43 @@
+Feature: disabling actions
+
+  As a developer with a slow action
+  I want to turn it off temporarily
+  So that I don't have to delete and later restore it.
+
+  - actions with "enabled: false" don't run
+  - the list of actions shows them greyed out
+
+
+  Scenario: a disabled action
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo unit tests for {{filename}}'
+        - match:
+            filename: '_integration\.js$'
+          command: 'echo integration tests for {{filename}}'
+          enabled: false
+      """
+    When sending the command:
+      """
+      {"filename": "one_integration.js"}
+      """
+    Then I see "unit tests for one_integration.js"
+
+
+  Scenario: listing disabled actions
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          enabled: false
+      """
+    When sending the command:
+      """
+      {"listActions": true}
+      """
+    Then I see "filename: \.js$  ->  echo testing {{filename}}  (disabled)"
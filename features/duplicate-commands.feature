@@ -0,0 +1,35 @@
+Feature: duplicate commands
+
+  As a developer using several tools that trigger test runs on save
+  I want identical commands that arrive at the same time to run only once
+  So that the second one doesn't stop the test run of the first one.
+
+  - when a command that starts a test run is identical to the previous one
+    and arrives within half a second of it, Tertestrial only acknowledges it
+  - the "client" key doesn't count for this comparison
+  - commands that control Tertestrial, like "repeatLastTest", always run
+
+
+  Background:
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+
+
+  Scenario: two clients sending the same command at the same time
+    When sending the command:
+      """
+      {"filename": "one.js", "client": "vim"}
+      """
+    And sending the command:
+      """
+      {"filename": "one.js", "client": "formatter"}
+      """
+    Then I see "command from formatter"
+    And I see "this command is identical to the one that just arrived, running it only once"
+    And I see "testing one.js"
+    And the process is still running
@@ -0,0 +1,75 @@
+Feature: .env files
+
+  As a developer whose test suite follows the dotenv conventions
+  I want commands to receive the variables of a .env file
+  So that I don't have to load it myself in every command.
+
+  - "envFile" names a .env file whose variables commands receive,
+    either for all actions at the top level of the configuration or for a single action
+  - variables of the .env file of an action override those of the global one,
+    which override the environment of the Tertestrial server
+  - unreadable .env files print an error and the command runs without them
+
+
+  Scenario: a .env file for all actions
+    Given a file ".env.test" with the content:
+      """
+      # test settings
+      DATABASE_URL=postgres://localhost/test
+      """
+    And Tertestrial runs with the configuration:
+      """
+      envFile: .env.test
+
+      actions:
+        - match:
+          command: 'echo testing against $DATABASE_URL'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "testing against postgres://localhost/test"
+
+
+  Scenario: a .env file for an action overrides the global one
+    Given a file ".env.test" with the content:
+      """
+      LOG_LEVEL=info
+      PORT=3000
+      """
+    And a file ".env.debug" with the content:
+      """
+      LOG_LEVEL=debug
+      """
+    And Tertestrial runs with the configuration:
+      """
+      envFile: .env.test
+
+      actions:
+        - match:
+          command: 'echo logging at $LOG_LEVEL on port $PORT'
+          envFile: .env.debug
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "logging at debug on port 3000"
+
+
+  Scenario: a missing .env file
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo running all tests'
+          envFile: .env.missing
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "cannot read the .env file .env.missing"
+    And I see "running all tests"
+    And the process is still running
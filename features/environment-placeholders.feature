@@ -0,0 +1,40 @@
+Feature: environment variable placeholders
+
+  As a developer sharing a configuration with my team
+  I want commands to use values from my environment
+  So that the shared configuration doesn't contain machine-specific values.
+
+  - commands can use environment variables of the Tertestrial server via "{{env.NAME}}"
+  - Tertestrial fills them in when running the command, so that the printed command shows their values
+  - undefined environment variables are empty
+
+
+  Scenario: using an environment variable
+    Given the environment variable "RUST_LOG" is "debug"
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.rs$'
+          command: 'echo testing {{filename}} with log level {{env.RUST_LOG}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.rs"}
+      """
+    Then I see "testing one.rs with log level debug"
+
+
+  Scenario: using an undefined environment variable
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.rs$'
+          command: 'echo testing {{filename}} with flags [{{env.TERTESTRIAL_UNDEFINED_FLAGS}}]'
+      """
+    When sending the command:
+      """
+      {"filename": "one.rs"}
+      """
+    Then I see "testing one.rs with flags []"
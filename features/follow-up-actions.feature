@@ -0,0 +1,72 @@
+Feature: follow-up actions
+
+  As a developer with several related checks
+  I want Tertestrial to run further actions depending on the result of a test
+  So that I can build simple local pipelines.
+
+  - actions can have a "name"
+  - "onPass" names the action to run after the action succeeded
+  - "onFail" names the action to run after the action failed
+  - follow-up actions run with the same command as the original action
+  - follow-up actions cannot lead back to the action that ran them
+
+
+  Background:
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'test {{filename}} = good.js'
+          onPass: lint
+          onFail: showLog
+
+        - name: lint
+          command: 'echo linting {{filename}}'
+
+        - name: showLog
+          command: 'echo showing the log for {{filename}}'
+      """
+
+
+  Scenario: running the follow-up action for success
+    When sending the command:
+      """
+      {"filename": "good.js"}
+      """
+    Then I see "running follow-up action lint"
+    And I see "linting good.js"
+    And the process is still running
+
+
+  Scenario: running the follow-up action for failure
+    When sending the command:
+      """
+      {"filename": "bad.js"}
+      """
+    Then I see "running follow-up action showLog"
+    And I see "showing the log for bad.js"
+    And the process is still running
+
+
+  Scenario: follow-up actions that run in a cycle
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - name: test
+          match:
+            filename: '\.js$'
+          command: 'test {{filename}} = good.js'
+          onFail: retry
+
+        - name: retry
+          command: 'echo retrying {{filename}}'
+          onPass: test
+      """
+    When trying to start tertestrial
+    Then I see:
+      """
+      Error: invalid configuration:
+      action 1 in action set default has follow-up actions that run in a cycle: test -> retry -> test
+      """
+    And the process ends
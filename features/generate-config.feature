@@ -6,6 +6,8 @@ Feature: generating the configuration file
 
   - run "tertestrial setup" to start the configuration wizard
   - the configuration wizard asks a number of questions and generates a configuration file
+  - it offers the configurations for the languages of the project first,
+    detected via files like Cargo.toml, package.json, or go.mod
 
 
   Scenario: generating a custom configuration file
@@ -14,3 +16,29 @@ Feature: generating the configuration file
     Then I see "created configuration file tertestrial.yml"
     And it creates a file "tertestrial.yml"
     And the process ends
+
+
+  Scenario: generating the configuration file for a detected language
+    Given a file "Cargo.toml" with the content:
+      """
+      [package]
+      name = "acme"
+      """
+    When starting 'tertestrial setup'
+    Then I see "This looks like a rust project"
+    When entering '[ENTER]'
+    Then I see "created configuration file tertestrial.yml"
+    And the file "tertestrial.yml" contains "cargo test"
+    And the process ends
+
+
+  Scenario: generating the configuration file for a given language
+    When running 'tertestrial setup --language ruby-cucumber-rspec'
+    Then I see "created configuration file tertestrial.yml"
+    And it creates a file "tertestrial.yml"
+
+
+  Scenario: generating the configuration file for an unknown language
+    When starting 'tertestrial setup --language zonk'
+    Then I see "Error: unknown language zonk, must be one of cpp-ctest, csharp-dotnet, elixir-mix, go-test, java-gradle, java-maven, js-cucumber-mocha, js-cucumber-mocha-api-cli, js-vitest, php-phpunit, python-pytest, ruby-cucumber-rspec, rust-cargo"
+    And the process ends
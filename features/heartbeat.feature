@@ -0,0 +1,25 @@
+Feature: heartbeats
+
+  As a developer running slow test suites
+  I want to see that a silent command is still alive
+  So that I can tell a slow test suite from a hung one.
+
+  - the "heartbeat" of an action is a number of seconds
+  - when its command doesn't print anything for that long,
+    Tertestrial prints how long it has been running
+
+
+  Scenario: a silent command
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'sleep 2 && echo done sleeping'
+          heartbeat: 1
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "still running (1s)…"
+    And I see "done sleeping"
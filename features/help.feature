@@ -5,6 +5,7 @@ Feature: Help screen
   So that I can be reminded how the command works.
 
   - run "tertestrial help" to display a help screen
+  - invalid arguments display the usage and exit with code 4
 
 
   Scenario: displaying the help screen
@@ -12,11 +13,43 @@ Feature: Help screen
     Then I see:
       """
       Usage:
-        tertestrial
-        tertestrial (help | setup | version)
+        tertestrial [--once] [--a11y] [--status-header] [--verbose] [--config=<path>] [--action-set=<name>]
+        tertestrial --print-pipe-path
+        tertestrial (env | help | version)
+        tertestrial list [<key>] [--json] [--config=<path>]
+        tertestrial run <name> [<filename>] [--config=<path>]
+        tertestrial setup [--language=<name>]
+        tertestrial [config] schema
+        tertestrial config check [--config=<path>]
+        tertestrial config show [--config=<path>]
+        tertestrial config migrate [--config=<path>]
 
       Subcommands:
-        help      Show this screen
-        setup     Run a setup wizard to generate a config file
-        version   Show version
+        env            Print shell commands that export the pipe path
+        help           Show this screen
+        list           List the actions, or only those that match commands by the given key
+        run            Run the action with the given name once, optionally for a file
+        setup          Run a setup wizard to generate a config file
+        version        Show version
+        config schema  Print the JSON Schema of the config file (also: schema)
+        config check   Report all problems in the config file
+        config show    Print the effective configuration as JSON
+        config migrate Upgrade the config file to the current format
+
+      Options:
+        --once               Exit after the first test run, with 0 if it passed and 1 if not
+        --a11y               Produce screen-reader friendly output
+        --status-header      Keep a status line at the top of the terminal
+        --verbose            Explain which actions match each command
+        --json               Print the list of actions as JSON
+        --config=<path>      Use the given config file instead of searching for one
+        --action-set=<name>  Start with the action set with the given name
+        --print-pipe-path    Print the path of the pipe that editors write commands to
+        --language=<name>    Set up the built-in configuration with the given name
       """
+
+
+  Scenario: invalid arguments
+    When starting 'tertestrial --zonk'
+    Then I see "Usage:"
+    And the process ends with exit code 4
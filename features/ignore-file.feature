@@ -0,0 +1,69 @@
+Feature: ignoring files
+
+  As a developer with generated code and vendored dependencies in my code base
+  I want to exclude these files from test runs
+  So that triggering them by accident never starts a test run.
+
+  - files listed in ".tertestrialignore" never start test runs
+  - so do files that match the "ignore" patterns of the configuration
+  - both use the gitignore syntax
+
+
+  Scenario: sending an ignored file
+    Given a file ".tertestrialignore" with the content:
+      """
+      generated/
+      *.pb.js
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "generated/one.js"}
+      """
+    Then I see "ignoring generated/one.js because of .tertestrialignore"
+    And the process is still running
+
+
+  Scenario: sending several files, some of them ignored
+    Given a file ".tertestrialignore" with the content:
+      """
+      *.pb.js
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            files: '\.js$'
+          command: 'echo testing {{files}}'
+      """
+    When sending the command:
+      """
+      {"files": ["one.js", "two.pb.js"]}
+      """
+    Then I see "ignoring two.pb.js because of .tertestrialignore"
+    And I see "testing one.js"
+    And the process is still running
+
+
+  Scenario: sending a file that the configuration ignores
+    Given Tertestrial runs with the configuration:
+      """
+      ignore:
+        - 'node_modules/**'
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "node_modules/chai/index.js"}
+      """
+    Then I see "ignoring node_modules/chai/index.js because of the ignore setting of the configuration"
+    And the process is still running
@@ -0,0 +1,31 @@
+Feature: placeholders about the previous run
+
+  As a developer writing adaptive test commands
+  I want commands to know how the previous test run went
+  So that they can, for example, stop at the first failure only after slow runs.
+
+  - "{{lastDuration}}" is the duration of the previous run in seconds
+  - "{{lastExitCode}}" is its exit code
+  - "{{lastResult}}" is "pass", "fail", or "build-error"
+  - they are also available as the environment variables
+    TERTESTRIAL_LAST_DURATION, TERTESTRIAL_LAST_EXIT_CODE, and TERTESTRIAL_LAST_RESULT
+  - they are empty before the first run
+
+
+  Scenario: using the result of the previous run
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo previous run: [{{lastResult}}] with exit code [$TERTESTRIAL_LAST_EXIT_CODE] && exit 2'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "previous run: [] with exit code []"
+    When sending the command:
+      """
+      {"repeatLastTest": true}
+      """
+    Then I see "previous run: [fail] with exit code [2]"
@@ -0,0 +1,99 @@
+Feature: listing the actions
+
+  As a developer or editor plugin author
+  I want to see which actions the configuration defines
+  So that I know which commands run for which files.
+
+  - run "tertestrial list" to print the actions of all action sets,
+    with the regular expressions they match and the placeholders their commands use
+  - disabled actions appear greyed out and marked as disabled
+  - "tertestrial list <key>" lists only the actions that match commands by that key
+  - "tertestrial list --json" prints the actions as JSON, for editor plugins
+
+
+  Scenario: listing all actions
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'mocha {{filename}}'
+        - name: lint
+          command: 'eslint .'
+      """
+    When running 'tertestrial list'
+    Then I see:
+      """
+      default:
+        filename: \.js$  ->  mocha {{filename}}
+          placeholders: filename
+        (lint)  ->  eslint .
+      """
+
+
+  Scenario: listing disabled actions
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'mocha {{filename}}'
+          enabled: false
+      """
+    When running 'tertestrial list'
+    Then I see "filename: \.js$  ->  mocha {{filename}}  (disabled)"
+
+
+  Scenario: listing the actions for a key
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'mocha {{filename}}'
+        - match:
+            filename: '\.js$'
+            line: '\d+'
+          command: 'mocha {{filename}}:{{line}}'
+        - match:
+            suite: 'all'
+          command: 'mocha'
+      """
+    When running 'tertestrial list line'
+    Then I see:
+      """
+      default:
+        filename: \.js$, line: \d+  ->  mocha {{filename}}:{{line}}
+          placeholders: filename, line
+      """
+
+
+  Scenario: listing the actions as JSON
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'mocha {{filename}}'
+      """
+    When running 'tertestrial list --json'
+    Then I see:
+      """
+      [
+        {
+          "name": "default",
+          "actions": [
+            {
+              "match": {
+                "filename": "/\\.js$/"
+              },
+              "command": "mocha {{filename}}",
+              "placeholders": [
+                "filename"
+              ],
+              "enabled": true
+            }
+          ]
+        }
+      ]
+      """
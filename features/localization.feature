@@ -0,0 +1,24 @@
+Feature: localized messages
+
+  As a developer who prefers a language other than English
+  I want Tertestrial to talk to me in my language
+  So that I understand its guidance more easily.
+
+  - Tertestrial picks the language from TERTESTRIAL_LANG
+    or the usual locale environment variables like LANG
+  - supported languages are English and German
+  - messages missing in a language are shown in English
+
+
+  Scenario: German messages
+    Given the environment variable "TERTESTRIAL_LANG" is "de"
+    When trying to start tertestrial
+    Then I see "Fehler: Konfigurationsdatei nicht gefunden"
+    And the process ends
+
+
+  Scenario: unsupported language
+    Given the environment variable "TERTESTRIAL_LANG" is "xx"
+    When trying to start tertestrial
+    Then I see "Error: cannot find configuration file"
+    And the process ends
@@ -0,0 +1,59 @@
+Feature: commands with several files
+
+  As a developer with several test files open
+  I want to run the tests in all of them with a single command
+  So that one keystroke in my editor tests everything I'm working on.
+
+  - the "files" key of a command contains a list of files
+  - actions match it if all files match their "files" pattern,
+    or any file if the configuration sets "filesMatch: any"
+  - "{{files}}" contains the files separated by spaces
+
+
+  Scenario: all files match
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            files: '\.js$'
+          command: 'echo testing {{files}}'
+      """
+    When sending the command:
+      """
+      {"files": ["one.js", "two.js"]}
+      """
+    Then I see "testing one.js two.js"
+    And the process is still running
+
+
+  Scenario: not all files match
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            files: '\.js$'
+          command: 'echo testing {{files}}'
+      """
+    When sending the command:
+      """
+      {"files": ["one.js", "two.rb"]}
+      """
+    Then I see "no matching action found"
+    And the process is still running
+
+
+  Scenario: any file matches
+    Given Tertestrial runs with the configuration:
+      """
+      filesMatch: any
+      actions:
+        - match:
+            files: '\.js$'
+          command: 'echo testing {{files}}'
+      """
+    When sending the command:
+      """
+      {"files": ["one.js", "two.rb"]}
+      """
+    Then I see "testing one.js two.rb"
+    And the process is still running
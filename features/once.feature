@@ -0,0 +1,37 @@
+Feature: running a single test
+
+  As a developer using Tertestrial inside scripts
+  I want Tertestrial to run one test and then exit with its result
+  So that I can use it as a blocking building block.
+
+  - run "tertestrial --once" to exit after the first test run
+  - Tertestrial exits with code 0 if the test succeeded and 1 if it failed
+
+
+  Scenario: a succeeding test
+    Given Tertestrial runs with the argument "--once" and the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo running all tests'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "running all tests"
+    And the process ends with exit code 0
+
+
+  Scenario: a failing test
+    Given Tertestrial runs with the argument "--once" and the configuration:
+      """
+      actions:
+        - match:
+          command: 'exit 3'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then the process ends with exit code 1
@@ -0,0 +1,45 @@
+Feature: saving the output of commands to a file
+
+  As a developer who archives the logs of local test runs
+  I want Tertestrial to write the output of commands to a file
+  So that I can look at it later.
+
+  - the "outputFile" field of an action names the file to write the output to
+  - it can contain placeholders
+  - missing directories get created
+  - if the file can't be written, the output only appears in the terminal
+
+
+  Scenario: writing the output to a file
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          outputFile: 'reports/{{fileStem}}.log'
+      """
+    When sending the command:
+      """
+      {"filename": "src/one.js"}
+      """
+    Then I see "testing src/one.js"
+    And the file "reports/one.log" contains "testing src/one.js"
+
+
+  Scenario: the output file cannot be written
+    Given a directory "reports"
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          outputFile: 'reports'
+      """
+    When sending the command:
+      """
+      {"filename": "src/one.js"}
+      """
+    Then I see "cannot write the output to"
+    And I see "testing src/one.js"
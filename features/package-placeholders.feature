@@ -0,0 +1,100 @@
+Feature: placeholders about packages
+
+  As a developer working in a monorepo
+  I want to know which package the current file belongs to
+  So that I can run only the tests of that package.
+
+  - "{{npmPackage}}" is the name of the nearest package.json above the file
+  - "{{packageDir}}" is the directory of that package.json
+  - "{{packageManager}}" is the package manager used in the code base
+  - "{{mixProjectDir}}" is the directory of the nearest mix.exs above the file,
+    "{{mixFilename}}" is the path of the file relative to it
+  - "{{gradleProject}}" is the Gradle project containing the file,
+    "{{mavenModule}}" is the directory of the nearest pom.xml above the file
+
+
+  Scenario: running a command in the package of a file
+    Given a directory "web"
+    And a file "web/package.json" with the content:
+      """
+      {"name": "@acme/web"}
+      """
+    And a file "yarn.lock" with the content:
+      """
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo {{packageManager}} runs {{npmPackage}} in {{packageDir}}'
+      """
+    When sending the command:
+      """
+      {"filename": "web/one.js"}
+      """
+    Then I see "yarn runs @acme/web in web"
+    And the process is still running
+
+
+  Scenario: a package.json that cannot be parsed
+    Given a directory "web"
+    And a file "web/package.json" with the content:
+      """
+      {"name": "@acme/web",
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo running {{npmPackage}}'
+      """
+    When sending the command:
+      """
+      {"filename": "web/one.js"}
+      """
+    Then I see "cannot determine the npm package of web/one.js"
+    And the process is still running
+
+
+  Scenario: running a command in the app of an Elixir umbrella project
+    Given a directory "apps"
+    And a directory "apps/billing"
+    And a file "apps/billing/mix.exs" with the content:
+      """
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.exs$'
+            line: '\d+'
+          command: 'echo running {{mixFilename}}:{{line}} in {{mixProjectDir}}'
+      """
+    When sending the command:
+      """
+      {"filename": "apps/billing/test/invoice_test.exs", "line": 12}
+      """
+    Then I see "running test/invoice_test.exs:12 in apps/billing"
+    And the process is still running
+
+
+  Scenario: running a command in the Gradle project of a file
+    Given a directory "app"
+    And a file "app/build.gradle.kts" with the content:
+      """
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.kt$'
+          command: 'echo running {{gradleProject}}:test'
+      """
+    When sending the command:
+      """
+      {"filename": "app/src/test/kotlin/UserTest.kt"}
+      """
+    Then I see "running :app:test"
+    And the process is still running
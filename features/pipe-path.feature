@@ -0,0 +1,24 @@
+Feature: finding the pipe
+
+  As a developer writing an editor plugin or script for Tertestrial
+  I want to ask Tertestrial where its pipe is
+  So that I don't have to hard-code its location.
+
+  - run "tertestrial --print-pipe-path" to print the path of the pipe
+  - run "tertestrial env" to print a shell command exporting it as TERTESTRIAL_PIPE
+
+
+  Scenario: printing the pipe path
+    When running 'tertestrial --print-pipe-path'
+    Then I see:
+      """
+      .tertestrial.tmp
+      """
+
+
+  Scenario: exporting the pipe path
+    When running 'tertestrial env'
+    Then I see:
+      """
+      export TERTESTRIAL_PIPE='
+      """
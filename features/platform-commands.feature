@@ -0,0 +1,43 @@
+Feature: commands that differ by operating system
+
+  As a developer on a team using different operating systems
+  I want to define platform-specific variants of a command
+  So that we can share one configuration file.
+
+  - the command of an action can be an object with a variant per platform
+  - supported platforms are "linux", "macos", "windows",
+    and the other names Node.js uses for platforms
+  - the "default" variant applies to all other platforms
+
+
+  Scenario: using the default variant
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command:
+            zonk-os: 'echo running on zonk-os'
+            default: 'echo running on another platform'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "running on another platform"
+    And the process is still running
+
+
+  Scenario: no variant for the current platform
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command:
+            zonk-os: 'echo running on zonk-os'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "this action has no command for the platform"
+    And the process is still running
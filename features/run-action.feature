@@ -0,0 +1,76 @@
+Feature: running an action by name
+
+  As a developer writing a new action
+  I want to run it directly from the command line
+  So that I can try it out without an editor, and invoke it from scripts.
+
+  - run "tertestrial run <name>" to run the action with the given name once
+  - an optional filename, like "foo.js:3", provides the file placeholders
+  - Tertestrial exits with code 0 if the action succeeded and 1 if it failed,
+    including when its command cannot run, for example because a placeholder has no value
+  - unknown actions exit with code 4
+
+
+  Scenario: running an action
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - name: lint
+          match:
+            filename: '\.js$'
+          command: 'echo linting all files'
+      """
+    When starting 'tertestrial run lint'
+    Then I see "linting all files"
+    And the process ends with exit code 0
+
+
+  Scenario: running an action for a file
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - name: test
+          match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}} line {{line}}'
+      """
+    When starting 'tertestrial run test one.js:3'
+    Then I see "testing one.js line 3"
+    And the process ends with exit code 0
+
+
+  Scenario: a failing action
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - name: broken
+          match:
+          command: 'exit 3'
+      """
+    When starting 'tertestrial run broken'
+    Then the process ends with exit code 1
+
+
+  Scenario: an action whose command cannot run
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - name: test
+          command: 'npm test --workspace {{npmPackage}}'
+      """
+    When starting 'tertestrial run test one.js'
+    Then I see "cannot find a package.json for one.js"
+    And the process ends with exit code 1
+
+
+  Scenario: an unknown action
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - name: lint
+          match:
+          command: 'echo linting'
+      """
+    When starting 'tertestrial run zonk'
+    Then I see "action zonk does not exist"
+    And the process ends with exit code 4
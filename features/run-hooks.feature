@@ -0,0 +1,50 @@
+Feature: commands before and after test runs
+
+  As a developer whose tests need preparation and cleanup
+  I want to run commands before and after each test run
+  So that I don't have to put them into every command.
+
+  - the "beforeRun" command runs before the command, which only runs if it succeeds
+  - the "afterRun" command runs after the command,
+    and can use the "{{exitCode}}" and "{{result}}" placeholders
+  - stopped commands don't run their "afterRun" command, and stopping a running "afterRun" command stops it
+  - both can be defined at the top level, and actions can replace them
+
+
+  Scenario: running commands before and after the command
+    Given Tertestrial runs with the configuration:
+      """
+      beforeRun: 'echo building'
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          afterRun: 'echo finished {{filename}} with {{exitCode}}: {{result}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see:
+      """
+      building
+      """
+    And I see "testing one.js"
+    And I see "finished one.js with 0: pass"
+
+
+  Scenario: a failing beforeRun command
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+          beforeRun: 'exit 2'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "the beforeRun command failed with exit code 2, not running the command"
+    And the process is still running
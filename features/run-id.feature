@@ -0,0 +1,26 @@
+Feature: run IDs
+
+  As a developer looking at the output of several test runs
+  I want each run to have a unique ID
+  So that I can tell which run produced which output.
+
+  - each test run gets a short unique ID
+  - Tertestrial prints the ID before running the command
+  - commands can access the ID via the "{{runId}}" placeholder
+    and the TERTESTRIAL_RUN_ID environment variable
+
+
+  Scenario: accessing the run ID
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo placeholder {{runId}} matches $TERTESTRIAL_RUN_ID'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "run "
+    And I see "matches"
+    And the process is still running
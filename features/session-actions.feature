@@ -0,0 +1,104 @@
+Feature: session actions
+
+  As an editor plugin developer
+  I want to add actions without touching the configuration file
+  So that my plugin can offer running a file with custom flags.
+
+  - '{"addAction": {...}}' adds a named action for the rest of the session
+  - session actions win over equally specific configured actions
+  - '{"listActions": true}' prints the configured and session actions
+  - '{"removeAction": "<name>"}' removes the session action with the given name
+
+
+  Scenario: adding a session action
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"addAction": {"name": "verbose", "match": {"filename": "\\.js$"}, "command": "echo verbosely testing {{filename}}"}}
+      """
+    Then I see "added the session action verbose"
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "verbosely testing one.js"
+
+
+  Scenario: listing the actions
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"addAction": {"name": "verbose", "match": {"filename": "\\.js$"}, "command": "echo verbosely testing {{filename}}"}}
+      """
+    And sending the command:
+      """
+      {"listActions": true}
+      """
+    Then I see "session actions:"
+    And I see "filename: \.js$  ->  echo verbosely testing {{filename}}"
+
+
+  Scenario: removing a session action
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"addAction": {"name": "verbose", "match": {"filename": "\\.js$"}, "command": "echo verbosely testing {{filename}}"}}
+      """
+    And sending the command:
+      """
+      {"removeAction": "verbose"}
+      """
+    Then I see "removed the session action verbose"
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "testing one.js"
+
+
+  Scenario: adding an action without a name
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo Running all tests'
+      """
+    When sending the command:
+      """
+      {"addAction": {"match": {}, "command": "echo custom"}}
+      """
+    Then I see "session actions need a name"
+    And the process is still running
+
+
+  Scenario: adding an invalid action
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo Running all tests'
+      """
+    When sending the command:
+      """
+      {"addAction": {"name": "broken", "match": {}}}
+      """
+    Then I see "the session action broken has no command"
+    And the process is still running
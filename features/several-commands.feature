@@ -0,0 +1,46 @@
+Feature: several commands per action
+
+  As a developer whose tests need a preparation step
+  I want an action to run several commands one after the other
+  So that I don't have to glue them together with the "&&" of a particular shell.
+
+  - the command of an action can be a list of commands
+  - they run one after the other, until one fails
+  - the exit code of the run is the one of the last command that ran
+
+
+  Scenario: all commands succeed
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command:
+            - 'echo generating code'
+            - 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "generating code"
+    And I see "testing one.js"
+    And I see "exit code: 0"
+
+
+  Scenario: a command fails
+    Given Tertestrial runs with the argument "--once" and the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command:
+            - 'exit 3'
+            - 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "exit code: 3"
+    And the process ends with exit code 1
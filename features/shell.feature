@@ -0,0 +1,63 @@
+Feature: choosing the shell
+
+  As a developer whose commands rely on features of my shell
+  I want to choose the shell that runs commands
+  So that I can use its functions and quoting rules.
+
+  - commands run via "sh -c" by default
+  - the "shell" setting, at the top level of the configuration or for an action,
+    is the program and arguments that run commands, with the command line as the last argument
+  - "shell: false" runs commands directly, split into arguments like a shell would
+    before filling in placeholders, so that placeholder values need no shell quoting
+
+
+  Scenario: running commands in another shell
+    Given Tertestrial runs with the configuration:
+      """
+      shell: ['bash', '-c']
+
+      actions:
+        - match:
+          command: 'echo running in $0'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "running in bash"
+
+
+  Scenario: running commands directly
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: "echo testing '{{filename}}' $HOME"
+          shell: false
+      """
+    When sending the command:
+      """
+      {"filename": "my test.js"}
+      """
+    Then I see "testing my test.js $HOME"
+
+
+  Scenario: placeholder values with spaces stay single arguments
+    Given a file "my test.js" with the content:
+      """
+      test
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'ls {{filename}}'
+          shell: false
+      """
+    When sending the command:
+      """
+      {"filename": "my test.js"}
+      """
+    Then I see "exit code: 0"
@@ -0,0 +1,56 @@
+Feature: plain-text shorthand for commands
+
+  As a developer testing Tertestrial by hand or writing a minimal editor integration
+  I want to send commands as plain text instead of JSON
+  So that they are quick to type and easy to generate.
+
+  - "<key> <value>" is a shorthand for {"<key>": "<value>"}
+  - "<key>" is a shorthand for {"<key>": true}
+  - filenames can contain the line as "<filename>:<line>"
+
+
+  Background:
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+
+        - match:
+            filename: '\.js$'
+            line: '\d+'
+          command: 'echo testing {{filename}} at line {{line}}'
+      """
+
+
+  Scenario: sending a filename
+    When sending the command:
+      """
+      filename one.js
+      """
+    Then I see "testing one.js"
+    And the process is still running
+
+
+  Scenario: sending a filename and line
+    When sending the command:
+      """
+      filename one.js:12
+      """
+    Then I see "testing one.js at line 12"
+    And the process is still running
+
+
+  Scenario: repeating the last test
+    When sending the command:
+      """
+      filename one.js
+      """
+    Then I see "testing one.js"
+    When sending the command:
+      """
+      repeatLastTest
+      """
+    Then I see "testing one.js"
+    And the process is still running
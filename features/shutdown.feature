@@ -0,0 +1,25 @@
+Feature: shutting down
+
+  As a developer done with my work
+  I want Tertestrial to clean up everything it started when I exit it
+  So that no test processes keep running in the background.
+
+  - hitting ctrl-c stops the running command before Tertestrial exits
+  - hitting ctrl-c again exits right away
+
+
+  Scenario: exiting while a command runs
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: "trap 'echo cleaning up; exit 0' TERM; while true; do sleep 0.1; done"
+      """
+    And sending the command:
+      """
+      {}
+      """
+    And I see "while true"
+    When stopping Tertestrial with ctrl-c
+    Then I see "cleaning up"
+    And the process ends
@@ -0,0 +1,26 @@
+Feature: status header
+
+  As a developer watching long test runs
+  I want to always see what Tertestrial is doing
+  So that I don't have to scroll up to find the current command.
+
+  - run "tertestrial --status-header" to keep a status line at the top of the terminal
+  - the status line shows the active action set, the current command and its duration,
+    and the result of the last run
+  - the output of commands scrolls beneath it
+  - the status header only appears in terminals, and not in screen reader mode
+
+
+  Scenario: running tests with the status header
+    Given Tertestrial runs with the argument "--status-header" and the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'echo testing {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "testing one.js"
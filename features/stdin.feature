@@ -0,0 +1,47 @@
+Feature: providing standard input to commands
+
+  As a developer using tools that read from standard input
+  I want to define what Tertestrial sends to the standard input of a command
+  So that I can drive these tools without creating temporary files.
+
+  - the "stdin" field of an action defines the text to send to the command
+  - the "stdinFrom" field of an action names a file whose content to send to the command
+  - both fields can contain placeholders
+
+
+  Scenario: providing the standard input as text
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+          command: 'cat'
+          stdin: 'checking {{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see "checking one.js"
+    And the process is still running
+
+
+  Scenario: providing the standard input from a file
+    Given a file "one.txt" with the content:
+      """
+      content of one.txt
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.txt$'
+          command: 'cat'
+          stdinFrom: '{{filename}}'
+      """
+    When sending the command:
+      """
+      {"filename": "one.txt"}
+      """
+    Then I see "content of one.txt"
+    And the process is still running
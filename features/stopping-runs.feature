@@ -6,6 +6,12 @@ Feature: Stopping the currently running test
 
   - send '{"stopCurrentTest": true}' to stop the currently running tests
   - if no test is running, this command does nothing
+  - actions can configure the signal that stops them ("killSignal"),
+    a grace period after which Tertestrial sends SIGKILL ("killTimeout"),
+    and whether to stop all processes the command started ("killProcessGroup")
+  - configurations with an unknown "killSignal" are invalid
+  - the "onCancel" command of an action runs after stopping its command,
+    whether by a stop command, a newer command, a timeout, or shutting down Tertestrial
 
 
   Scenario: stopping a running test
@@ -50,3 +56,81 @@ Feature: Stopping the currently running test
       """
     Then I see "you have already stopped the last command"
     And the process is still running
+
+
+  Scenario: stopping with a custom signal
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: "trap 'echo caught the interrupt; exit 0' INT; while true; do sleep 0.1; done"
+          killSignal: SIGINT
+      """
+    And sending the command:
+      """
+      {}
+      """
+    When sending the command:
+      """
+      {"stopCurrentTest": true}
+      """
+    Then I see "caught the interrupt"
+    And the process is still running
+
+
+  Scenario: escalating to SIGKILL after the grace period
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: "trap '' TERM; while true; do sleep 0.1; done"
+          killTimeout: 0.5
+      """
+    And sending the command:
+      """
+      {}
+      """
+    When sending the command:
+      """
+      {"stopCurrentTest": true}
+      """
+    Then I see "exit code: null"
+    And the process is still running
+
+
+  Scenario: cleaning up after stopping a test
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'sleep 10'
+          onCancel: 'echo cleaning up after run {{runId}}'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    And sending the command:
+      """
+      {"stopCurrentTest": true}
+      """
+    Then I see "stopping the currently running command"
+    And I see "cleaning up after run"
+    And the process is still running
+
+
+  Scenario: an unknown stop signal
+    Given a file "tertestrial.yml" with the content:
+      """
+      actions:
+        - match:
+          command: 'echo never runs'
+          killSignal: SIGSTOPP
+      """
+    When trying to start tertestrial
+    Then I see:
+      """
+      Error: invalid configuration:
+      action 1 in action set default has the unknown killSignal SIGSTOPP
+      """
+    And the process ends
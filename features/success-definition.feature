@@ -0,0 +1,92 @@
+Feature: defining what counts as a successful test run
+
+  As a developer using commands whose exit codes don't follow the usual conventions
+  I want to tell Tertestrial which results count as success
+  So that the pass/fail reporting matches what the command actually means.
+
+  - the "ignoreExitCodes" list of an action contains exit codes that count as success
+  - the "successOutput" regex of an action makes a run successful
+    only if the output of the command matches it
+  - the "buildErrorOutput" regex of an action marks failed runs whose output matches it
+    as build errors rather than test failures, which "afterRun" commands see as the result "build-error"
+
+
+  Scenario: ignored exit code
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'exit 1'
+          ignoreExitCodes: [1]
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "exit code: 1 (treated as success)"
+    And the process is still running
+
+
+  Scenario: output that indicates success
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo 0 failures ; exit 2'
+          successOutput: '\b0 failures'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "exit code: 2 (treated as success)"
+    And the process is still running
+
+
+  Scenario: output that indicates failure
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo 3 failures'
+          successOutput: '\b0 failures'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "exit code: 0 (treated as failure)"
+    And the process is still running
+
+
+  Scenario: output that indicates a build error
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo "error[E0308]: mismatched types" ; exit 101'
+          buildErrorOutput: '^error(\[E\d+\])?:'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "exit code: 101 (build error)"
+    And the process is still running
+
+
+  Scenario: notifying about a build error
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo "error[E0308]: mismatched types" ; exit 101'
+          buildErrorOutput: '^error(\[E\d+\])?:'
+          afterRun: 'echo result: {{result}}'
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "result: build-error"
+    And the process is still running
@@ -0,0 +1,112 @@
+Feature: placeholders about the test at a line
+
+  As a developer using a test runner that selects tests by name
+  I want to know the names of the test at the line my cursor is on
+  So that I can run just that test.
+
+  - "{{testNamespace}}" is the namespace declared above the line
+  - "{{testClass}}" is the class declared above the line
+  - "{{testMethod}}" is the method declared above the line
+  - "{{testName}}" is the name of the test block above the line
+  - "{{testNamePattern}}" is a regular expression that matches this name literally
+  - commands whose test placeholders cannot be determined don't run
+
+
+  Scenario: running the test method at a line
+    Given a file "UserTest.php" with the content:
+      """
+      <?php
+      class UserTest extends TestCase
+      {
+          public function testCreation()
+          {
+              $this->assertTrue(true);
+          }
+      }
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.php$'
+            line: '\d+'
+          command: "echo running {{testClass}}::{{testMethod}}"
+      """
+    When sending the command:
+      """
+      {"filename": "UserTest.php", "line": 6}
+      """
+    Then I see "running UserTest::testCreation"
+    And the process is still running
+
+
+  Scenario: running the JavaScript test block at a line
+    Given a file "user.test.js" with the content:
+      """
+      describe('users', () => {
+        it('creates users', () => {
+          expect(true).toBe(true)
+        })
+      })
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+            line: '\d+'
+          command: "echo running {{testName}}"
+      """
+    When sending the command:
+      """
+      {"filename": "user.test.js", "line": 3}
+      """
+    Then I see "running creates users"
+    And the process is still running
+
+
+  Scenario: running the JavaScript test block at a line by a pattern
+    Given a file "user.test.js" with the content:
+      """
+      it('creates users (admin)', () => {
+        expect(true).toBe(true)
+      })
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.js$'
+            line: '\d+'
+          command: "echo running {{testNamePattern}}"
+          shell: false
+      """
+    When sending the command:
+      """
+      {"filename": "user.test.js", "line": 2}
+      """
+    Then I see "running creates users \(admin\)"
+    And the process is still running
+
+
+  Scenario: no test at the line
+    Given a file "helpers.go" with the content:
+      """
+      package users
+
+      var count = 0
+      """
+    And Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+            filename: '\.go$'
+            line: '\d+'
+          command: "echo running {{testMethod}}"
+      """
+    When sending the command:
+      """
+      {"filename": "helpers.go", "line": 3}
+      """
+    Then I see "cannot determine {{testMethod}} for helpers.go, not running the command"
+    And the process is still running
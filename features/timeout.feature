@@ -0,0 +1,47 @@
+Feature: timeouts
+
+  As a developer with integration tests that sometimes hang
+  I want Tertestrial to stop commands that run too long
+  So that a hung test doesn't block the server.
+
+  - the "timeout" of an action is the number of seconds after which Tertestrial stops its command
+  - Tertestrial stops it with the "killSignal" of the action,
+    together with all processes it started,
+    and kills them with SIGKILL if they haven't ended after the "killTimeout" of the action or 5 seconds
+  - timed out runs count as failed
+
+
+  Scenario: a command that runs too long
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo starting the hanging test && sleep 10'
+          timeout: 1
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "starting the hanging test"
+    And I see "the command ran longer than 1s, stopping it"
+    And I see "(timed out)"
+    And the process is still running
+
+
+  Scenario: a command that ignores the stop signal
+    Given Tertestrial runs with the configuration:
+      """
+      actions:
+        - match:
+          command: "trap '' TERM; echo starting the stubborn test; while true; do sleep 0.1; done"
+          timeout: 1
+          killTimeout: 0.5
+      """
+    When sending the command:
+      """
+      {}
+      """
+    Then I see "starting the stubborn test"
+    And I see "(timed out)"
+    And the process is still running
@@ -0,0 +1,43 @@
+Feature: explaining which actions match
+
+  As a developer whose editor runs an unexpected command
+  I want to see why each action matches a command or not
+  So that I can fix my configuration.
+
+  - run "tertestrial --verbose" to print for each incoming command
+    whether each action matches it, and why not
+
+
+  Scenario: explaining the matching actions
+    Given Tertestrial runs with the argument "--verbose" and the configuration:
+      """
+      actions:
+        - match:
+          command: 'echo running all tests'
+        - match:
+            filename: '\.rb$'
+          command: 'echo rspec {{filename}}'
+        - match:
+            filename: '\.js$'
+            line: '\d+'
+          command: 'echo mocha {{filename}}:{{line}}'
+        - match:
+            filename: '\.js$'
+          command: 'echo mocha {{filename}}'
+        - name: lint
+          command: 'echo eslint'
+      """
+    When sending the command:
+      """
+      {"filename": "one.js"}
+      """
+    Then I see:
+      """
+      matching {"filename":"one.js"}:
+        {}: only matches empty commands
+        filename: \.rb$: filename one.js doesn't match /\.rb$/
+        filename: \.js$, line: \d+: the command has no line
+        filename: \.js$: matches with specificity 1
+        (lint): only runs by name
+      """
+    And I see "mocha one.js"